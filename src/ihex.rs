@@ -36,4 +36,133 @@ impl IHex {
             Self::StartLinearAddress(_) => types::START_LINEAR_ADDRESS,
         }
     }
+
+    /// Builds the record sequence for a flat binary blob loaded at `base`,
+    /// the inverse of [`crate::MemoryMap`].
+    ///
+    /// `data` is chunked into `Data` records of at most `bytes_per_record`
+    /// bytes (clamped to at least one byte), split further so no record
+    /// ever crosses a `0x10000` linear-address page boundary. An
+    /// `ExtendedLinearAddress` record is inserted whenever the upper 16
+    /// bits of the running address change, and the sequence is
+    /// terminated with `EndOfFile`.
+    #[cfg(feature = "alloc")]
+    pub fn from_binary(base: u32, data: &[u8], bytes_per_record: u8) -> Vec<IHex> {
+        let bytes_per_record = (bytes_per_record as usize).max(1);
+
+        let mut records = Vec::new();
+        let mut high_address = None;
+        let mut address = base;
+        let mut remaining = data;
+
+        while !remaining.is_empty() {
+            let high = (address >> 16) as u16;
+
+            if high_address != Some(high) {
+                records.push(IHex::ExtendedLinearAddress(high));
+                high_address = Some(high);
+            }
+
+            let until_page_end = 0x10000 - (address & 0xFFFF) as usize;
+            let record_len = remaining.len().min(bytes_per_record).min(until_page_end);
+
+            let (chunk, rest) = remaining.split_at(record_len);
+
+            records.push(IHex::Data {
+                bytes: chunk.to_vec(),
+                length: chunk.len() as u8,
+                offset: (address & 0xFFFF) as u16,
+            });
+
+            address += chunk.len() as u32;
+            remaining = rest;
+        }
+
+        records.push(IHex::EndOfFile);
+
+        records
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn from_binary_chunks_and_terminates() {
+        let records = IHex::from_binary(0x0010, &[1, 2, 3, 4, 5], 2);
+
+        assert_eq!(
+            records,
+            vec![
+                IHex::ExtendedLinearAddress(0x0000),
+                IHex::Data {
+                    bytes: vec![1, 2],
+                    length: 2,
+                    offset: 0x0010,
+                },
+                IHex::Data {
+                    bytes: vec![3, 4],
+                    length: 2,
+                    offset: 0x0012,
+                },
+                IHex::Data {
+                    bytes: vec![5],
+                    length: 1,
+                    offset: 0x0014,
+                },
+                IHex::EndOfFile,
+            ]
+        );
+    }
+
+    #[test]
+    fn from_binary_inserts_ela_on_rollover() {
+        let records = IHex::from_binary(0xFFFE, &[1, 2, 3, 4], 2);
+
+        assert_eq!(
+            records,
+            vec![
+                IHex::ExtendedLinearAddress(0x0000),
+                IHex::Data {
+                    bytes: vec![1, 2],
+                    length: 2,
+                    offset: 0xFFFE,
+                },
+                IHex::ExtendedLinearAddress(0x0001),
+                IHex::Data {
+                    bytes: vec![3, 4],
+                    length: 2,
+                    offset: 0x0000,
+                },
+                IHex::EndOfFile,
+            ]
+        );
+    }
+
+    #[test]
+    fn from_binary_splits_record_straddling_page_boundary() {
+        let records = IHex::from_binary(0xFFFE, &[1, 2, 3, 4], 4);
+
+        assert_eq!(
+            records,
+            vec![
+                IHex::ExtendedLinearAddress(0x0000),
+                IHex::Data {
+                    bytes: vec![1, 2],
+                    length: 2,
+                    offset: 0xFFFE,
+                },
+                IHex::ExtendedLinearAddress(0x0001),
+                IHex::Data {
+                    bytes: vec![3, 4],
+                    length: 2,
+                    offset: 0x0000,
+                },
+                IHex::EndOfFile,
+            ]
+        );
+    }
 }
@@ -3,10 +3,17 @@
 mod checksum;
 mod error;
 mod ihex;
+#[cfg(feature = "alloc")]
+mod memory_map;
 mod parser;
+mod reader;
 mod serializer;
 mod types;
 
 pub use error::*;
 pub use ihex::*;
+#[cfg(feature = "alloc")]
+pub use memory_map::*;
 pub use parser::*;
+pub use reader::*;
+pub use serializer::SerializeError;
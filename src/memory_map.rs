@@ -0,0 +1,215 @@
+use crate::IHex;
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+/// The address space a record's 16-bit offset is resolved against.
+#[derive(Clone, Copy)]
+enum AddressSpace {
+    /// Flat addressing, or addressing following an `ExtendedLinearAddress`
+    /// record: `base` is added to `offset` with no wrapping.
+    Linear,
+    /// Addressing following an `ExtendedSegmentAddress` record: `offset`
+    /// wraps at `0x10000` rather than carrying into `base`.
+    Segment,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MemoryMapError {
+    Overlap { at: u32 },
+}
+
+/// A flat view of an Intel HEX image, folded from per-record offsets into
+/// absolute 32-bit addresses.
+///
+/// Intel HEX records only carry a 16-bit offset plus the extended address
+/// records needed to relocate it; [`MemoryMap::from_records`] tracks that
+/// running state for the caller and hands back sorted, coalesced
+/// `(address, bytes)` segments.
+#[derive(Debug, PartialEq)]
+pub struct MemoryMap {
+    segments: Vec<(u32, Vec<u8>)>,
+}
+
+impl MemoryMap {
+    /// Folds a stream of records into a [`MemoryMap`].
+    ///
+    /// Stops at the first `EndOfFile` record, if any. Two records that
+    /// place data at the same absolute address are reported as
+    /// [`MemoryMapError::Overlap`].
+    pub fn from_records<I>(records: I) -> Result<Self, MemoryMapError>
+    where
+        I: IntoIterator<Item = IHex>,
+    {
+        let mut space = AddressSpace::Linear;
+        let mut base: u32 = 0;
+        let mut bytes: Vec<(u32, u8)> = Vec::new();
+
+        for record in records {
+            match record {
+                IHex::ExtendedLinearAddress(ela) => {
+                    base = (ela as u32) << 16;
+                    space = AddressSpace::Linear;
+                }
+                IHex::ExtendedSegmentAddress(esa) => {
+                    base = (esa as u32) << 4;
+                    space = AddressSpace::Segment;
+                }
+                IHex::Data {
+                    bytes: data,
+                    length,
+                    offset,
+                } => {
+                    for (address, chunk) in place(space, base, offset, &data[..length as usize]) {
+                        bytes.extend(
+                            chunk
+                                .iter()
+                                .enumerate()
+                                .map(|(i, &b)| (address + i as u32, b)),
+                        );
+                    }
+                }
+                IHex::EndOfFile => break,
+                IHex::StartSegmentAddress { .. } | IHex::StartLinearAddress(_) => {}
+            }
+        }
+
+        bytes.sort_by_key(|&(address, _)| address);
+
+        if let Some(&(at, _)) = bytes.windows(2).find_map(|pair| {
+            if pair[0].0 == pair[1].0 {
+                Some(&pair[1])
+            } else {
+                None
+            }
+        }) {
+            return Err(MemoryMapError::Overlap { at });
+        }
+
+        Ok(MemoryMap {
+            segments: coalesce(bytes),
+        })
+    }
+
+    /// The resolved segments, sorted by address and coalesced so that
+    /// adjacent bytes share one entry.
+    pub fn segments(&self) -> &[(u32, Vec<u8>)] {
+        &self.segments
+    }
+}
+
+/// Splits `data` into at most two `(address, bytes)` pieces, wrapping the
+/// offset at the `0x10000` segment boundary when `space` is `Segment`.
+fn place(space: AddressSpace, base: u32, offset: u16, data: &[u8]) -> Vec<(u32, &[u8])> {
+    match space {
+        AddressSpace::Linear => alloc::vec![(base + offset as u32, data)],
+        AddressSpace::Segment => {
+            let start = offset as u32;
+            let end = start + data.len() as u32;
+
+            if end <= 0x10000 {
+                alloc::vec![(base + start, data)]
+            } else {
+                let first_len = (0x10000 - start) as usize;
+                alloc::vec![
+                    (base + start, &data[..first_len]),
+                    (base, &data[first_len..]),
+                ]
+            }
+        }
+    }
+}
+
+/// Merges a sorted, duplicate-free `(address, byte)` list into contiguous
+/// `(address, bytes)` runs.
+fn coalesce(bytes: Vec<(u32, u8)>) -> Vec<(u32, Vec<u8>)> {
+    let mut segments = Vec::new();
+    let mut iter = bytes.into_iter().peekable();
+
+    while let Some((start, byte)) = iter.next() {
+        let mut run = alloc::vec![byte];
+        let mut expected = start + 1;
+
+        while let Some(&(address, _)) = iter.peek() {
+            if address != expected {
+                break;
+            }
+
+            let (_, byte) = iter.next().unwrap();
+            run.push(byte);
+            expected += 1;
+        }
+
+        segments.push((start, run));
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn data(offset: u16, bytes: &[u8]) -> IHex {
+        IHex::Data {
+            bytes: bytes.to_vec(),
+            length: bytes.len() as u8,
+            offset,
+        }
+    }
+
+    #[test]
+    fn flat_addressing() {
+        let records = vec![data(0x0010, &[1, 2, 3]), IHex::EndOfFile];
+
+        let map = MemoryMap::from_records(records).unwrap();
+
+        assert_eq!(map.segments(), &[(0x0010, vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn linear_addressing_relocates_and_coalesces() {
+        let records = vec![
+            IHex::ExtendedLinearAddress(0x0001),
+            data(0x0000, &[1, 2]),
+            data(0x0002, &[3, 4]),
+            IHex::EndOfFile,
+        ];
+
+        let map = MemoryMap::from_records(records).unwrap();
+
+        assert_eq!(map.segments(), &[(0x0001_0000, vec![1, 2, 3, 4])]);
+    }
+
+    #[test]
+    fn segment_addressing_wraps_within_64k() {
+        let records = vec![
+            IHex::ExtendedSegmentAddress(0x1000),
+            data(0xFFFE, &[1, 2, 3, 4]),
+            IHex::EndOfFile,
+        ];
+
+        let map = MemoryMap::from_records(records).unwrap();
+
+        let base = 0x1000u32 << 4;
+        assert_eq!(
+            map.segments(),
+            &[(base, vec![3, 4]), (base + 0xFFFE, vec![1, 2])]
+        );
+    }
+
+    #[test]
+    fn detects_overlap() {
+        let records = vec![
+            data(0x0000, &[1, 2, 3]),
+            data(0x0001, &[9, 9]),
+            IHex::EndOfFile,
+        ];
+
+        assert_eq!(
+            MemoryMap::from_records(records),
+            Err(MemoryMapError::Overlap { at: 0x0001 })
+        );
+    }
+}
@@ -4,31 +4,59 @@ use crate::IHex;
 use core::iter::FusedIterator;
 use core::str::FromStr;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
+    Empty,
     MissingColon,
+    OddLength,
+    TooShort,
     ParseError,
     BadChecksum(u8, u8),
     BadLength,
     BadType,
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
 }
 
 type ParseResult = Result<IHex, ParseError>;
 
+/// Minimum decoded byte length of a record: length + 2-byte address +
+/// record type + checksum, before any data bytes.
+const MIN_RECORD_LEN: usize = 5;
+
 impl IHex {
     pub fn parse<T: AsRef<[u8]>>(line: T) -> ParseResult {
         let line = line.as_ref();
 
+        if line.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
         if line[0] != b':' {
             return Err(ParseError::MissingColon);
         }
 
         let line = &line[1..];
 
-        let mut bytes = [0; 0x200];
+        if line.len() % 2 != 0 {
+            return Err(ParseError::OddLength);
+        }
 
         let length = line.len() / 2;
 
+        if length < MIN_RECORD_LEN {
+            return Err(ParseError::TooShort);
+        }
+
+        let mut bytes = [0; 0x200];
+
+        if length > bytes.len() {
+            return Err(ParseError::BadLength);
+        }
+
         if hex::decode_to_slice(line, &mut bytes[..length]).is_err() {
             return Err(ParseError::ParseError);
         }
@@ -58,9 +86,15 @@ impl IHex {
 
         match record_type {
             types::DATA => {
-                let mut bytes = [0; 0xFF];
+                #[cfg(feature = "alloc")]
+                let bytes = data.to_vec();
 
-                bytes[..data.len()].clone_from_slice(data);
+                #[cfg(not(feature = "alloc"))]
+                let bytes = {
+                    let mut bytes = [0; 0xFF];
+                    bytes[..data.len()].clone_from_slice(data);
+                    bytes
+                };
 
                 Ok(IHex::Data {
                     bytes,
@@ -70,37 +104,48 @@ impl IHex {
             }
             types::END_OF_FILE => Ok(IHex::EndOfFile),
             types::EXTENDED_SEGMENT_ADDRESS => {
-                let mut short = [0; 2];
-
-                short.clone_from_slice(&data[0..2]);
-                let address = u16::from_be_bytes(short);
+                let address = u16::from_be_bytes(
+                    data.get(0..2)
+                        .ok_or(ParseError::BadLength)?
+                        .try_into()
+                        .unwrap(),
+                );
 
                 Ok(IHex::ExtendedSegmentAddress(address))
             }
             types::START_SEGMENT_ADDRESS => {
-                let mut short = [0; 2];
-
-                short.clone_from_slice(&data[0..2]);
-                let cs = u16::from_be_bytes(short);
-
-                short.clone_from_slice(&data[2..4]);
-                let ip = u16::from_be_bytes(short);
+                let cs = u16::from_be_bytes(
+                    data.get(0..2)
+                        .ok_or(ParseError::BadLength)?
+                        .try_into()
+                        .unwrap(),
+                );
+                let ip = u16::from_be_bytes(
+                    data.get(2..4)
+                        .ok_or(ParseError::BadLength)?
+                        .try_into()
+                        .unwrap(),
+                );
 
                 Ok(IHex::StartSegmentAddress { cs, ip })
             }
             types::EXTENDED_LINEAR_ADDRESS => {
-                let mut short = [0; 2];
-
-                short.clone_from_slice(&data[0..2]);
-                let ela = u16::from_be_bytes(short);
+                let ela = u16::from_be_bytes(
+                    data.get(0..2)
+                        .ok_or(ParseError::BadLength)?
+                        .try_into()
+                        .unwrap(),
+                );
 
                 Ok(IHex::ExtendedLinearAddress(ela))
             }
             types::START_LINEAR_ADDRESS => {
-                let mut word = [0; 4];
-
-                word.clone_from_slice(&data[0..4]);
-                let sla = u32::from_be_bytes(word);
+                let sla = u32::from_be_bytes(
+                    data.get(0..4)
+                        .ok_or(ParseError::BadLength)?
+                        .try_into()
+                        .unwrap(),
+                );
 
                 Ok(IHex::StartLinearAddress(sla))
             }
@@ -165,8 +210,16 @@ mod tests {
             0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x67, 0x61, 0x70,
         ];
 
-        let mut bytes = [0; 0xFF];
-        bytes[..expected.len()].clone_from_slice(&expected);
+        #[cfg(feature = "alloc")]
+        let bytes = expected.to_vec();
+
+        #[cfg(not(feature = "alloc"))]
+        let bytes = {
+            let mut bytes = [0; 0xFF];
+            bytes[..expected.len()].clone_from_slice(&expected);
+            bytes
+        };
+
         let data = IHex::Data {
             bytes,
             length: expected.len() as u8,
@@ -214,6 +267,29 @@ mod tests {
         assert_eq!(":0400000512345678E3".parse(), Ok(sla));
     }
 
+    #[test]
+    fn parse_empty() {
+        assert_eq!(IHex::parse(""), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn parse_odd_length() {
+        assert_eq!(IHex::parse(":000"), Err(ParseError::OddLength));
+    }
+
+    #[test]
+    fn parse_too_short() {
+        assert_eq!(IHex::parse(":00"), Err(ParseError::TooShort));
+    }
+
+    #[test]
+    fn parse_truncated_fixed_field() {
+        // A well-formed, well-checksummed ExtendedLinearAddress record
+        // whose data is only 1 byte long instead of the 2 the record type
+        // requires.
+        assert_eq!(IHex::parse(":01000004ab50"), Err(ParseError::BadLength));
+    }
+
     #[test]
     fn multi_line_parser() {
         let ela = IHex::ExtendedLinearAddress(0xABCD);
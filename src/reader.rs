@@ -0,0 +1,183 @@
+use crate::{IHex, ParseError};
+
+/// Strips a single trailing `\r` left over from CRLF line endings.
+fn strip_cr(line: &[u8]) -> &[u8] {
+    match line.split_last() {
+        Some((b'\r', rest)) => rest,
+        _ => line,
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_reader {
+    use super::{strip_cr, IHex, ParseError};
+    use std::io::{BufRead, BufReader, Read};
+    use std::vec::Vec;
+
+    /// Decodes one record at a time from a [`Read`] stream, buffering a
+    /// single line at a time rather than the whole input.
+    pub struct Reader<R> {
+        inner: BufReader<R>,
+        scratch: Vec<u8>,
+        done: bool,
+    }
+
+    impl<R: Read> Reader<R> {
+        pub fn new(inner: R) -> Self {
+            Reader {
+                inner: BufReader::new(inner),
+                scratch: Vec::new(),
+                done: false,
+            }
+        }
+
+        fn next_line(&mut self) -> Option<std::io::Result<&[u8]>> {
+            loop {
+                self.scratch.clear();
+
+                match self.inner.read_until(b'\n', &mut self.scratch) {
+                    Ok(0) => return None,
+                    Ok(_) => {
+                        if self.scratch.last() == Some(&b'\n') {
+                            self.scratch.pop();
+                        }
+
+                        let line = strip_cr(&self.scratch);
+
+                        if line.is_empty() {
+                            continue;
+                        }
+
+                        let len = line.len();
+                        return Some(Ok(&self.scratch[..len]));
+                    }
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+        }
+    }
+
+    impl<R: Read> Iterator for Reader<R> {
+        type Item = Result<IHex, ParseError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.done {
+                return None;
+            }
+
+            match self.next_line() {
+                None => {
+                    self.done = true;
+                    None
+                }
+                Some(Err(err)) => {
+                    self.done = true;
+                    Some(Err(ParseError::Io(err.kind())))
+                }
+                Some(Ok(line)) => Some(IHex::parse(line)),
+            }
+        }
+    }
+
+    impl IHex {
+        /// Decodes records one at a time from a [`std::io::Read`] stream.
+        pub fn read_from<R: Read>(reader: R) -> Reader<R> {
+            Reader::new(reader)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use std_reader::Reader;
+
+#[cfg(not(feature = "std"))]
+mod slice_reader {
+    use super::{strip_cr, IHex, ParseError};
+
+    /// Decodes one record at a time from an in-memory byte slice, the
+    /// `no_std` counterpart of [`super::Reader`].
+    pub struct SliceReader<'a> {
+        remaining: &'a [u8],
+    }
+
+    impl<'a> SliceReader<'a> {
+        fn next_line(&mut self) -> Option<&'a [u8]> {
+            loop {
+                if self.remaining.is_empty() {
+                    return None;
+                }
+
+                let (line, rest) = match self.remaining.iter().position(|&b| b == b'\n') {
+                    Some(pos) => (&self.remaining[..pos], &self.remaining[pos + 1..]),
+                    None => (self.remaining, &[][..]),
+                };
+
+                self.remaining = rest;
+
+                let line = strip_cr(line);
+
+                if !line.is_empty() {
+                    return Some(line);
+                }
+            }
+        }
+    }
+
+    impl<'a> Iterator for SliceReader<'a> {
+        type Item = Result<IHex, ParseError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.next_line().map(IHex::parse)
+        }
+    }
+
+    impl IHex {
+        /// Takes the bytes out of `*bytes`, leaving it empty, and returns
+        /// an iterator that decodes them one record at a time.
+        pub fn read_from<'a>(bytes: &mut &'a [u8]) -> SliceReader<'a> {
+            let reader = SliceReader { remaining: *bytes };
+            *bytes = &[];
+            reader
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub use slice_reader::SliceReader;
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_from_reader() {
+        let ela = IHex::ExtendedLinearAddress(0xABCD);
+        let sla = IHex::StartLinearAddress(0x12345678);
+
+        let data = b":02000004ABCD82\r\n\r\n:0400000512345678E3\r\n".as_ref();
+        let mut reader = IHex::read_from(data);
+
+        assert_eq!(reader.next(), Some(Ok(ela)));
+        assert_eq!(reader.next(), Some(Ok(sla)));
+        assert_eq!(reader.next(), None);
+    }
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_from_slice() {
+        let ela = IHex::ExtendedLinearAddress(0xABCD);
+        let sla = IHex::StartLinearAddress(0x12345678);
+
+        let mut data: &[u8] = b":02000004ABCD82\r\n\r\n:0400000512345678E3\r\n";
+        let mut reader = IHex::read_from(&mut data);
+
+        assert_eq!(reader.next(), Some(Ok(ela)));
+        assert_eq!(reader.next(), Some(Ok(sla)));
+        assert_eq!(reader.next(), None);
+        assert!(data.is_empty());
+    }
+}
@@ -1,50 +1,101 @@
 use crate::checksum::checksum;
 use crate::IHex;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[derive(Debug, PartialEq)]
+pub enum SerializeError {
+    BufferTooSmall { needed: usize, got: usize },
+    BadLength { declared: usize, available: usize },
+    EncodeError,
+}
+
 impl IHex {
-    pub fn serialize<T>(&self, buffer: &mut T) -> Result<usize, ()>
+    pub fn serialize<T>(&self, buffer: &mut T) -> Result<usize, SerializeError>
     where
         T: AsMut<[u8]>,
     {
-        let record_type = self.record_type();
-
-        match self {
-            Self::Data {
-                bytes,
-                length,
-                offset,
-            } => format(record_type, *offset, &bytes[..*length as usize], buffer),
-            Self::EndOfFile => format(record_type, 0, &[], buffer),
-            Self::ExtendedSegmentAddress(address) => {
-                format(record_type, 0, &address.to_be_bytes(), buffer)
-            }
-            Self::StartSegmentAddress { cs, ip } => {
-                let mut word = [0; 4];
-                word[..2].copy_from_slice(&cs.to_be_bytes());
-                word[2..].copy_from_slice(&ip.to_be_bytes());
+        format_record(self, buffer.as_mut())
+    }
 
-                format(record_type, 0, &word, buffer)
-            }
-            Self::ExtendedLinearAddress(address) => {
-                format(record_type, 0, &address.to_be_bytes(), buffer)
-            }
-            Self::StartLinearAddress(address) => {
-                format(record_type, 0, &address.to_be_bytes(), buffer)
+    /// Writes this record as encoded Intel HEX into `w`, without a trailing
+    /// line terminator.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> Result<(), SerializeError> {
+        let mut buffer = [0; 2 * (5 + 0xFF) + 1];
+        let length = format_record(self, &mut buffer)?;
+
+        w.write_all(&buffer[..length])
+            .map_err(|_| SerializeError::EncodeError)
+    }
+
+    /// Writes this record as encoded Intel HEX into `w`, without a trailing
+    /// line terminator.
+    #[cfg(not(feature = "std"))]
+    pub fn write_to<W: core::fmt::Write>(&self, w: &mut W) -> Result<(), SerializeError> {
+        let mut buffer = [0; 2 * (5 + 0xFF) + 1];
+        let length = format_record(self, &mut buffer)?;
+
+        let line =
+            core::str::from_utf8(&buffer[..length]).map_err(|_| SerializeError::EncodeError)?;
+
+        w.write_str(line).map_err(|_| SerializeError::EncodeError)
+    }
+}
+
+fn format_record(record: &IHex, buffer: &mut [u8]) -> Result<usize, SerializeError> {
+    let record_type = record.record_type();
+
+    match record {
+        IHex::Data {
+            bytes,
+            length,
+            offset,
+        } => {
+            let length = *length as usize;
+
+            if bytes.len() < length {
+                return Err(SerializeError::BadLength {
+                    declared: length,
+                    available: bytes.len(),
+                });
             }
+
+            format(record_type, *offset, &bytes[..length], buffer)
         }
+        IHex::EndOfFile => format(record_type, 0, &[], buffer),
+        IHex::ExtendedSegmentAddress(address) => {
+            format(record_type, 0, &address.to_be_bytes(), buffer)
+        }
+        IHex::StartSegmentAddress { cs, ip } => {
+            let mut word = [0; 4];
+            word[..2].copy_from_slice(&cs.to_be_bytes());
+            word[2..].copy_from_slice(&ip.to_be_bytes());
+
+            format(record_type, 0, &word, buffer)
+        }
+        IHex::ExtendedLinearAddress(address) => {
+            format(record_type, 0, &address.to_be_bytes(), buffer)
+        }
+        IHex::StartLinearAddress(address) => format(record_type, 0, &address.to_be_bytes(), buffer),
     }
 }
 
-fn format<T>(record_type: u8, offset: u16, data: &[u8], buffer: &mut T) -> Result<usize, ()>
-where
-    T: AsMut<[u8]>,
-{
-    let buffer = buffer.as_mut();
+fn format(
+    record_type: u8,
+    offset: u16,
+    data: &[u8],
+    buffer: &mut [u8],
+) -> Result<usize, SerializeError> {
     let data_length = 1 + 2 + 1 + data.len() + 1;
 
     let buffer_length = 2 * data_length + 1;
     if buffer.len() < buffer_length {
-        // Freak out
+        return Err(SerializeError::BufferTooSmall {
+            needed: buffer_length,
+            got: buffer.len(),
+        });
     }
 
     let mut bytes = [0; 0x200];
@@ -57,9 +108,8 @@ where
 
     buffer[0] = b':';
 
-    if hex::encode_to_slice(&bytes[..data_length], &mut buffer[1..buffer_length]).is_err() {
-        // Freak out
-    }
+    hex::encode_to_slice(&bytes[..data_length], &mut buffer[1..buffer_length])
+        .map_err(|_| SerializeError::EncodeError)?;
 
     Ok(buffer_length)
 }
@@ -74,8 +124,15 @@ mod tests {
             0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x67, 0x61, 0x70,
         ];
 
-        let mut bytes = [0; 0xFF];
-        bytes[..expected.len()].copy_from_slice(&expected);
+        #[cfg(feature = "alloc")]
+        let bytes = expected.to_vec();
+
+        #[cfg(not(feature = "alloc"))]
+        let bytes = {
+            let mut bytes = [0; 0xFF];
+            bytes[..expected.len()].copy_from_slice(&expected);
+            bytes
+        };
 
         let record = IHex::Data {
             bytes,
@@ -89,6 +146,26 @@ mod tests {
         assert_eq!(&buffer[..length], b":0b0010006164647265737320676170a7");
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn serialize_data_declared_length_too_long() {
+        let record = IHex::Data {
+            bytes: alloc::vec![1, 2, 3],
+            length: 10,
+            offset: 0,
+        };
+
+        let mut buffer = [0; 0x200];
+
+        assert_eq!(
+            record.serialize(&mut buffer),
+            Err(SerializeError::BadLength {
+                declared: 10,
+                available: 3
+            })
+        );
+    }
+
     #[test]
     fn serialize_eof() {
         let record = IHex::EndOfFile;
@@ -141,4 +218,27 @@ mod tests {
 
         assert_eq!(&buffer[..length], b":0400000512345678e3");
     }
+
+    #[test]
+    fn serialize_buffer_too_small() {
+        let record = IHex::EndOfFile;
+
+        let mut buffer = [0; 4];
+
+        assert_eq!(
+            record.serialize(&mut buffer),
+            Err(SerializeError::BufferTooSmall { needed: 11, got: 4 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_to_writer() {
+        let record = IHex::EndOfFile;
+
+        let mut out = std::vec::Vec::new();
+        record.write_to(&mut out).unwrap();
+
+        assert_eq!(out, b":00000001ff");
+    }
 }